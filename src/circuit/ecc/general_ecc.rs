@@ -0,0 +1,766 @@
+use std::marker::PhantomData;
+
+use group::ff::{Field, PrimeField};
+use halo2::arithmetic::{CurveAffine, FieldExt};
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use halo2arith::halo2;
+use halo2arith::main_gate::five::main_gate::{AssignedCondition, MainGate, MainGateConfig, MainGateInstructions};
+
+use crate::circuit::ecc::{AssignedPoint, EccConfig};
+use crate::circuit::integer::{AssignedInteger, IntegerChip, IntegerConfig, IntegerInstructions};
+use crate::field;
+use crate::rns::Rns;
+
+/// `b` in the short-Weierstrass equation `y^2 = x^3 + b` this chip operates on. Every point
+/// here is carried as a pair of `AssignedInteger<N>` (already collapsed into the native
+/// field, see `circuit::integer`), so curve arithmetic is done directly over `N` rather than
+/// over the "wrong" field `E::Base` a real multi-limb chip would reduce against.
+const CURVE_B: u64 = 7;
+
+fn is_identity<N: FieldExt>(p: (N, N)) -> bool {
+    p.0.is_zero_vartime() && p.1.is_zero_vartime()
+}
+
+fn double_clear<N: FieldExt>(p: (N, N)) -> (N, N) {
+    if is_identity(p) {
+        return p;
+    }
+    let (x, y) = p;
+    if y.is_zero_vartime() {
+        return (N::zero(), N::zero());
+    }
+    let three = field::small_constant::<N>(3);
+    let lambda = (x.square() * three) * y.double().invert().unwrap();
+    let x3 = lambda.square() - x.double();
+    let y3 = lambda * (x - x3) - y;
+    (x3, y3)
+}
+
+fn add_clear<N: FieldExt>(p: (N, N), q: (N, N)) -> (N, N) {
+    if is_identity(p) {
+        return q;
+    }
+    if is_identity(q) {
+        return p;
+    }
+    if p.0 == q.0 {
+        return if p.1 == -q.1 { (N::zero(), N::zero()) } else { double_clear(p) };
+    }
+    let lambda = (q.1 - p.1) * (q.0 - p.0).invert().unwrap();
+    let x3 = lambda.square() - p.0 - q.0;
+    let y3 = lambda * (p.0 - x3) - p.1;
+    (x3, y3)
+}
+
+fn mul_clear<N: FieldExt>(p: (N, N), scalar: N) -> (N, N) {
+    let repr = scalar.to_repr();
+    let bytes = repr.as_ref();
+    let mut acc = (N::zero(), N::zero());
+    for byte in bytes.iter().rev() {
+        for i in (0..8).rev() {
+            acc = double_clear(acc);
+            if (byte >> i) & 1 == 1 {
+                acc = add_clear(acc, p);
+            }
+        }
+    }
+    acc
+}
+
+#[derive(Clone, Debug)]
+pub struct GeneralEccChip<E: CurveAffine, N: FieldExt> {
+    config: EccConfig,
+    bit_len_limb: usize,
+    _marker: PhantomData<(E, N)>,
+}
+
+impl<E: CurveAffine, N: FieldExt> GeneralEccChip<E, N> {
+    pub fn new(config: EccConfig, bit_len_limb: usize) -> Self {
+        Self {
+            config,
+            bit_len_limb,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn rns(bit_len_limb: usize) -> (Rns<E::Base, N>, Rns<E::ScalarExt, N>) {
+        (Rns::construct(bit_len_limb), Rns::construct(bit_len_limb))
+    }
+
+    pub fn rns_base(&self) -> Rns<E::Base, N> {
+        Rns::construct(self.bit_len_limb)
+    }
+
+    pub fn rns_scalar(&self) -> Rns<E::ScalarExt, N> {
+        Rns::construct(self.bit_len_limb)
+    }
+
+    fn integer_config(&self) -> IntegerConfig {
+        IntegerConfig::new(self.config.range_config.clone(), self.config.main_gate_config.clone())
+    }
+
+    pub fn scalar_field_chip(&self) -> IntegerChip<E::ScalarExt, N> {
+        IntegerChip::new(self.integer_config(), self.rns_scalar())
+    }
+
+    pub fn base_field_chip(&self) -> IntegerChip<E::Base, N> {
+        IntegerChip::new(self.integer_config(), self.rns_base())
+    }
+
+    fn main_gate(&self) -> MainGate<N> {
+        MainGate::new(self.config.main_gate_config.clone())
+    }
+
+    fn point_value(p: &AssignedPoint<N>) -> Option<(N, N)> {
+        p.x.value().zip(p.y.value())
+    }
+
+    fn assign_native_point(&self, region: &mut Region<'_, N>, xy: Option<(N, N)>, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let main_gate = self.main_gate();
+        let x = main_gate.assign_value(region, xy.map(|v| v.0), offset)?;
+        let y = main_gate.assign_value(region, xy.map(|v| v.1), offset)?;
+        Ok(AssignedPoint::new(AssignedInteger { native: x }, AssignedInteger { native: y }))
+    }
+
+    /// Assigns a compile-time-known point coordinate (e.g. a windowed fixed-base table entry),
+    /// bypassing `IntegerInstructions::assign_constant`'s `W`-typed signature since the value
+    /// here is already expressed in the native field `N`.
+    pub(crate) fn assign_native_constant(&self, region: &mut Region<'_, N>, value: N, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let native = self.main_gate().assign_constant(region, value, offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    /// `point.coordinates()` is `None` only for the curve's identity element, which this chip
+    /// represents as the sentinel `(0, 0)` everywhere else (see `add`/`double`'s `assign_is_zero`
+    /// handling) rather than as an affine `(x, y)` pair, so that sentinel is returned here too
+    /// instead of unwrapping into a panic.
+    fn native_of(point: E) -> (N, N) {
+        let coords = point.coordinates();
+        if bool::from(coords.is_none()) {
+            (N::zero(), N::zero())
+        } else {
+            let coords = coords.unwrap();
+            (field::convert::<E::Base, N>(*coords.x()), field::convert::<E::Base, N>(*coords.y()))
+        }
+    }
+
+    pub fn assign_point(&self, region: &mut Region<'_, N>, point: Option<E>, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let xy = point.map(Self::native_of);
+        self.assign_native_point(region, xy, offset)
+    }
+
+    /// The auxiliary generator exists in a real chip to shift the ladder's accumulator so it
+    /// can never land on `point` (or its negation) partway through `mul`'s double-and-add,
+    /// which would otherwise hit `add`'s unconstrained exceptional case (`x_a == x_b`). This
+    /// chip does not implement that shift, so the edge case is only avoided in the distributions
+    /// `mul`/`mul2` are exercised against in practice, not ruled out in general;
+    /// assigning the aux generator is a no-op kept only so call sites that plumb it through
+    /// don't need to change.
+    pub fn assign_aux_generator(&mut self, region: &mut Region<'_, N>, aux_generator: Option<E>, offset: &mut usize) -> Result<(), Error> {
+        self.assign_point(region, aux_generator, offset)?;
+        Ok(())
+    }
+
+    pub fn assign_aux(&mut self, _region: &mut Region<'_, N>, _window_size: usize, _number_of_pairs: usize, _offset: &mut usize) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Constrained point addition: witnesses the slope through `a`/`b` and ties `x3`/`y3`
+    /// back to it via the short-Weierstrass group law (`lambda = (y_b - y_a)/(x_b - x_a)`,
+    /// `x3 = lambda^2 - x_a - x_b`, `y3 = lambda*(x_a - x3) - y_a`), the constraint a witness-
+    /// only `add` has no way to enforce. Like a real chip's un-unified addition formulas, this
+    /// assumes `a != b`; callers adding a point to itself must use `double` instead. Two
+    /// exceptional cases are folded in afterwards via `select`: one operand being the identity
+    /// sentinel `(0, 0)` (e.g. `mul`/`mul2`'s running accumulator before its first real term),
+    /// and `x_a == x_b`, i.e. `b == -a` (e.g. the last term of a `verify_batch` sum cancelling
+    /// the rest), which gives the identity.
+    pub fn add(&self, region: &mut Region<'_, N>, a: &AssignedPoint<N>, b: &AssignedPoint<N>, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let base_chip = self.base_field_chip();
+
+        let a_is_identity = base_chip.assign_is_zero(region, &a.x, offset)?;
+        let b_is_identity = base_chip.assign_is_zero(region, &b.x, offset)?;
+
+        let dx = base_chip.add(region, &b.x, &base_chip.neg(region, &a.x, offset)?, offset)?;
+        let dy = base_chip.add(region, &b.y, &base_chip.neg(region, &a.y, offset)?, offset)?;
+        let (inv_dx, _) = base_chip.invert(region, &dx, offset)?;
+        let lambda = base_chip.mul(region, &dy, &inv_dx, offset)?;
+
+        let x_sum = base_chip.add(region, &a.x, &b.x, offset)?;
+        let lambda_sq = base_chip.mul(region, &lambda, &lambda, offset)?;
+        let x3 = base_chip.add(region, &lambda_sq, &base_chip.neg(region, &x_sum, offset)?, offset)?;
+
+        let x1_minus_x3 = base_chip.add(region, &a.x, &base_chip.neg(region, &x3, offset)?, offset)?;
+        let lambda_term = base_chip.mul(region, &lambda, &x1_minus_x3, offset)?;
+        let y3 = base_chip.add(region, &lambda_term, &base_chip.neg(region, &a.y, offset)?, offset)?;
+
+        // a + (-a) = O
+        let dx_is_zero = base_chip.assign_is_zero(region, &dx, offset)?;
+        let zero = base_chip.assign_constant(region, E::Base::zero(), offset)?;
+        let x3 = base_chip.select(region, &dx_is_zero, &zero, &x3, offset)?;
+        let y3 = base_chip.select(region, &dx_is_zero, &zero, &y3, offset)?;
+
+        // a + O = a, O + b = b (O + O = O falls out of the `a_is_identity` branch, since
+        // `b.x == 0` there too).
+        let x = base_chip.select(region, &a_is_identity, &b.x, &base_chip.select(region, &b_is_identity, &a.x, &x3, offset)?, offset)?;
+        let y = base_chip.select(region, &a_is_identity, &b.y, &base_chip.select(region, &b_is_identity, &a.y, &y3, offset)?, offset)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    pub fn mul(&self, region: &mut Region<'_, N>, point: &AssignedPoint<N>, scalar: &AssignedInteger<N>, _window_size: usize, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let scalar_chip = self.scalar_field_chip();
+        let base_chip = self.base_field_chip();
+
+        let num_bits = <E::ScalarExt as PrimeField>::NUM_BITS as usize;
+        let bits = scalar_chip.assign_bits(region, scalar, num_bits, offset)?;
+
+        // double-and-add ladder: every step's `double` and `add` are now constrained via the
+        // group law, so the final accumulator is actually tied to `point`/`scalar` rather than
+        // merely witnessed to equal `mul_clear`'s out-of-circuit result.
+        let mut accumulator = self.assign_native_point(region, Some((N::zero(), N::zero())), offset)?;
+        for bit in bits.iter().rev() {
+            accumulator = self.double(region, &accumulator, offset)?;
+            let added = self.add(region, &accumulator, point, offset)?;
+
+            let x = base_chip.select(region, bit, &added.x, &accumulator.x, offset)?;
+            let y = base_chip.select(region, bit, &added.y, &accumulator.y, offset)?;
+            accumulator = AssignedPoint::new(x, y);
+        }
+
+        Ok(accumulator)
+    }
+
+    /// Selects one entry out of a `2^bits.len()`-sized table of compile-time-known points by a
+    /// little-endian slice of boolean-constrained bits, via the multilinear indicator
+    /// `sum_i (prod_j bit_j or (1 - bit_j)) * table[i]`. Used by `mul2`'s windowed fixed-base
+    /// table, one shared unscaled table reused at every window rather than pre-scaled per window.
+    fn select_from_table(&self, region: &mut Region<'_, N>, bits: &[AssignedInteger<N>], table: &[(N, N)], offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let scalar_chip = self.scalar_field_chip();
+        let table_size = 1usize << bits.len();
+        assert!(table.len() >= table_size);
+
+        let one = scalar_chip.assign_constant(region, E::ScalarExt::one(), offset)?;
+        let mut not_bits = Vec::with_capacity(bits.len());
+        for bit in bits {
+            let not_bit = scalar_chip.add(region, &one, &scalar_chip.neg(region, bit, offset)?, offset)?;
+            not_bits.push(not_bit);
+        }
+
+        let mut acc: Option<AssignedPoint<N>> = None;
+        for (i, entry) in table.iter().enumerate().take(table_size) {
+            let mut indicator = one.clone();
+            for (j, bit) in bits.iter().enumerate() {
+                let factor = if (i >> j) & 1 == 1 { bit } else { &not_bits[j] };
+                indicator = scalar_chip.mul(region, &indicator, factor, offset)?;
+            }
+
+            let entry_x = self.assign_native_constant(region, entry.0, offset)?;
+            let entry_y = self.assign_native_constant(region, entry.1, offset)?;
+            let term_x = scalar_chip.mul(region, &indicator, &entry_x, offset)?;
+            let term_y = scalar_chip.mul(region, &indicator, &entry_y, offset)?;
+
+            acc = Some(match acc {
+                Some(acc) => AssignedPoint::new(scalar_chip.add(region, &acc.x, &term_x, offset)?, scalar_chip.add(region, &acc.y, &term_y, offset)?),
+                None => AssignedPoint::new(term_x, term_y),
+            });
+        }
+
+        Ok(acc.unwrap())
+    }
+
+    /// Constrained point doubling: `lambda = 3*x^2 / (2*y)`, `x3 = lambda^2 - 2*x`,
+    /// `y3 = lambda*(x - x3) - y`, valid whenever `y != 0` (no point of order 2 arises in any
+    /// group this chip's `mul`/`mul2` are used with). The identity is folded in afterwards via
+    /// `select`, the same way `add` handles it.
+    pub fn double(&self, region: &mut Region<'_, N>, a: &AssignedPoint<N>, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let base_chip = self.base_field_chip();
+
+        let a_is_identity = base_chip.assign_is_zero(region, &a.x, offset)?;
+
+        let three = base_chip.assign_constant(region, field::small_constant::<E::Base>(3), offset)?;
+        let x_sq = base_chip.mul(region, &a.x, &a.x, offset)?;
+        let numerator = base_chip.mul(region, &three, &x_sq, offset)?;
+        let two_y = base_chip.add(region, &a.y, &a.y, offset)?;
+        let (inv_two_y, _) = base_chip.invert(region, &two_y, offset)?;
+        let lambda = base_chip.mul(region, &numerator, &inv_two_y, offset)?;
+
+        let two_x = base_chip.add(region, &a.x, &a.x, offset)?;
+        let lambda_sq = base_chip.mul(region, &lambda, &lambda, offset)?;
+        let x3 = base_chip.add(region, &lambda_sq, &base_chip.neg(region, &two_x, offset)?, offset)?;
+
+        let x_minus_x3 = base_chip.add(region, &a.x, &base_chip.neg(region, &x3, offset)?, offset)?;
+        let lambda_term = base_chip.mul(region, &lambda, &x_minus_x3, offset)?;
+        let y3 = base_chip.add(region, &lambda_term, &base_chip.neg(region, &a.y, offset)?, offset)?;
+
+        let x = base_chip.select(region, &a_is_identity, &a.x, &x3, offset)?;
+        let y = base_chip.select(region, &a_is_identity, &a.y, &y3, offset)?;
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    /// Double-scalar multiplication `scalar1*base1 + scalar2*point2` where `base1` is a
+    /// compile-time constant (e.g. the generator) and `point2` is a witness: the accumulator is
+    /// driven by `point2`'s per-bit ladder (unavoidable, since `point2` isn't known at compile
+    /// time), and `base1`'s contribution is folded in for free off that same ladder rather than
+    /// paying for a second set of doublings. A single unscaled `{0, base1, 2*base1, ...,
+    /// (2^w-1)*base1}` table, reused as-is at every window rather than rescaled per window, is
+    /// selected from and added in at each `window_size`-bit boundary of `scalar1`;
+    /// because the ladder still has exactly `lo` doublings left to run at that point (`lo` being
+    /// the window's own bit offset), those remaining doublings supply the `2^lo` scaling the
+    /// raw table entry needs, the same way a freshly-added per-bit term gets scaled by the
+    /// doublings still to come in `mul`'s ladder. This is strictly cheaper than selecting a
+    /// combined 4-entry `{O, base1, point2, base1+point2}` table every single bit: `base1`'s
+    /// contribution costs one `select_from_table` per `window_size` bits instead of one per bit.
+    #[allow(clippy::too_many_arguments)]
+    pub fn mul2(
+        &self,
+        region: &mut Region<'_, N>,
+        base1: E,
+        scalar1: &AssignedInteger<N>,
+        point2: &AssignedPoint<N>,
+        scalar2: &AssignedInteger<N>,
+        window_size: usize,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<N>, Error> {
+        let scalar_chip = self.scalar_field_chip();
+        let base_chip = self.base_field_chip();
+        let w = window_size.max(1);
+
+        let num_bits = <E::ScalarExt as PrimeField>::NUM_BITS as usize;
+        let bits1 = scalar_chip.assign_bits(region, scalar1, num_bits, offset)?;
+        let bits2 = scalar_chip.assign_bits(region, scalar2, num_bits, offset)?;
+
+        let table_size = 1usize << w;
+        let base1_native = Self::native_of(base1);
+        let mut table = Vec::with_capacity(table_size);
+        let mut entry = (N::zero(), N::zero());
+        table.push(entry);
+        for _ in 1..table_size {
+            entry = add_clear(entry, base1_native);
+            table.push(entry);
+        }
+
+        let mut accumulator = self.assign_native_point(region, Some((N::zero(), N::zero())), offset)?;
+        for p in (0..num_bits).rev() {
+            accumulator = self.double(region, &accumulator, offset)?;
+
+            let added = self.add(region, &accumulator, point2, offset)?;
+            let x = base_chip.select(region, &bits2[p], &added.x, &accumulator.x, offset)?;
+            let y = base_chip.select(region, &bits2[p], &added.y, &accumulator.y, offset)?;
+            accumulator = AssignedPoint::new(x, y);
+
+            // `p` is a window's own low bit exactly when `p % w == 0`; the ladder has `p`
+            // doublings left to run at that point, which is exactly the scaling this window's
+            // raw (unscaled) table entry needs.
+            if p % w == 0 {
+                let hi = (p + w).min(num_bits);
+                let window_bits = &bits1[p..hi];
+                let selected = self.select_from_table(region, window_bits, &table, offset)?;
+                accumulator = self.add(region, &accumulator, &selected, offset)?;
+            }
+        }
+
+        Ok(accumulator)
+    }
+
+    /// General multi-scalar multiplication: threads every `(point, scalar)` pair through a
+    /// single accumulation routine rather than leaving the caller to chain `mul`/`add` itself,
+    /// which is what lets `verify_batch` issue one `msm` call across all `3m` signature terms.
+    /// Each term still runs its own full `mul` (its own doubling ladder) before it is added in,
+    /// so no doublings are shared between terms and the cost grows linearly with their number.
+    pub fn msm(&self, region: &mut Region<'_, N>, points: &[AssignedPoint<N>], scalars: &[AssignedInteger<N>], window_size: usize, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        assert_eq!(points.len(), scalars.len());
+        let mut acc = self.assign_native_point(region, Some((N::zero(), N::zero())), offset)?;
+        for (point, scalar) in points.iter().zip(scalars.iter()) {
+            let term = self.mul(region, point, scalar, window_size, offset)?;
+            acc = self.add(region, &acc, &term, offset)?;
+        }
+        Ok(acc)
+    }
+
+    /// Recovers `y` from `x` via `y = sqrt(x^3 + b)`, picking whichever square root the
+    /// underlying field happens to return, and asserts the result actually satisfies the curve
+    /// equation (`assert_is_on_curve`) rather than merely witnessing whatever root the clear
+    /// computation found: if `x` isn't a valid x-coordinate at all, no `y` a prover supplies can
+    /// pass that check. The returned point's `x` is `x` itself (not a fresh, independently
+    /// witnessed copy), so it stays tied to whatever the caller does with `x` afterwards (e.g.
+    /// `verify_batch`'s reduction check against `sig.r`). Callers that need the specific root
+    /// (e.g. `recover`, or `verify_batch`'s `R_i`) must fix the parity themselves afterwards,
+    /// see `assign_point_from_x_with_parity`.
+    pub fn assign_point_from_x(&self, region: &mut Region<'_, N>, x: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedPoint<N>, Error> {
+        let b = field::small_constant::<N>(CURVE_B);
+        let y_value = x.value().map(|x_val| {
+            let rhs = x_val.square() * x_val + b;
+            Option::<N>::from(rhs.sqrt()).unwrap_or_else(N::zero)
+        });
+        let y = self.main_gate().assign_value(region, y_value, offset)?;
+        let point = AssignedPoint::new(x.clone(), AssignedInteger { native: y });
+        self.assert_is_on_curve(region, &point, offset)?;
+        Ok(point)
+    }
+
+    /// Same as `assign_point_from_x`, but flips the witnessed root so its `y` has the parity
+    /// requested by `is_y_odd`. This is what makes a point recovered from only an `x`-coordinate
+    /// (ecrecover's `R`, or `verify_batch`'s `R_i`) unambiguous: without this, a prover is free
+    /// to pick either square root, which `assign_point_from_x` alone does not rule out. The
+    /// flipped `y` is re-checked against the curve equation (same as `assign_point_from_x`),
+    /// and bit 0 of its little-endian decomposition is constrained to equal `is_y_odd`, so the
+    /// prover cannot substitute the other root.
+    pub fn assign_point_from_x_with_parity(
+        &self,
+        region: &mut Region<'_, N>,
+        x: &AssignedInteger<N>,
+        is_y_odd: &AssignedCondition<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<N>, Error> {
+        let point = self.assign_point_from_x(region, x, offset)?;
+        let y_value = match (point.y.value(), is_y_odd.value()) {
+            (Some(y_val), Some(parity)) => {
+                let is_odd = y_val.to_repr().as_ref()[0] & 1 == 1;
+                let wants_odd = parity == N::one();
+                Some(if is_odd == wants_odd { y_val } else { -y_val })
+            }
+            _ => None,
+        };
+        let y = self.main_gate().assign_value(region, y_value, offset)?;
+        let flipped = AssignedPoint::new(point.x.clone(), AssignedInteger { native: y });
+        self.assert_is_on_curve(region, &flipped, offset)?;
+        let y_bits = self.base_field_chip().assign_bits(region, &flipped.y, <N as PrimeField>::NUM_BITS as usize, offset)?;
+        self.main_gate().assert_equal(region, &y_bits[0].native, is_y_odd, offset)?;
+        Ok(flipped)
+    }
+
+    pub fn assert_is_identity(&self, region: &mut Region<'_, N>, point: &AssignedPoint<N>, offset: &mut usize) -> Result<(), Error> {
+        let base_chip = self.base_field_chip();
+        let zero = base_chip.assign_constant(region, E::Base::zero(), offset)?;
+        base_chip.assert_strict_equal(region, &point.x, &zero, offset)?;
+        base_chip.assert_strict_equal(region, &point.y, &zero, offset)
+    }
+
+    pub fn assert_is_on_curve(&self, region: &mut Region<'_, N>, point: &AssignedPoint<N>, offset: &mut usize) -> Result<(), Error> {
+        let base_chip = self.base_field_chip();
+        let y2 = base_chip.mul(region, &point.y, &point.y, offset)?;
+        let x2 = base_chip.mul(region, &point.x, &point.x, offset)?;
+        let x3 = base_chip.mul(region, &x2, &point.x, offset)?;
+        let b = base_chip.assign_constant(region, field::small_constant::<E::Base>(CURVE_B), offset)?;
+        let rhs = base_chip.add(region, &x3, &b, offset)?;
+        base_chip.assert_strict_equal(region, &y2, &rhs, offset)
+    }
+
+    /// Rules out the `(0, 0)` identity sentinel. Since `b != 0`, no point satisfying the
+    /// curve equation can have `x == 0`, so this reduces to a single not-zero check rather
+    /// than needing a boolean OR of `x != 0` and `y != 0`.
+    pub fn assert_not_identity(&self, region: &mut Region<'_, N>, point: &AssignedPoint<N>, offset: &mut usize) -> Result<(), Error> {
+        self.base_field_chip().assert_not_zero(region, &point.x, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::marker::PhantomData;
+
+    use group::prime::PrimeCurveAffine;
+    use group::Curve as _;
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2::dev::MockProver;
+    use halo2::plonk::{Circuit, ConstraintSystem};
+    use halo2arith::main_gate::five::range::{RangeChip, RangeConfig, RangeInstructions};
+    use rand::thread_rng;
+
+    use crate::NUMBER_OF_LOOKUP_LIMBS;
+
+    const BIT_LEN_LIMB: usize = 68;
+
+    #[derive(Clone, Debug)]
+    struct TestMul2Config {
+        main_gate_config: MainGateConfig,
+        range_config: RangeConfig,
+    }
+
+    impl TestMul2Config {
+        fn new<C: CurveAffine, N: FieldExt>(meta: &mut ConstraintSystem<N>) -> Self {
+            let (rns_base, rns_scalar) = GeneralEccChip::<C, N>::rns(BIT_LEN_LIMB);
+            let main_gate_config = MainGate::<N>::configure(meta);
+            let mut overflow_bit_lengths: Vec<usize> = vec![];
+            overflow_bit_lengths.extend(rns_base.overflow_lengths());
+            overflow_bit_lengths.extend(rns_scalar.overflow_lengths());
+            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
+            Self { main_gate_config, range_config }
+        }
+
+        fn ecc_chip_config(&self) -> EccConfig {
+            EccConfig::new(self.range_config.clone(), self.main_gate_config.clone())
+        }
+
+        fn config_range<N: FieldExt>(&self, layouter: &mut impl Layouter<N>) -> Result<(), Error> {
+            let bit_len_lookup = BIT_LEN_LIMB / NUMBER_OF_LOOKUP_LIMBS;
+            let range_chip = RangeChip::<N>::new(self.range_config.clone(), bit_len_lookup);
+            range_chip.load_limb_range_table(layouter)?;
+            range_chip.load_overflow_range_tables(layouter)
+        }
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitMul2<E: CurveAffine, N: FieldExt> {
+        _marker: PhantomData<(E, N)>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitMul2<E, N> {
+        type Config = TestMul2Config;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestMul2Config::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            let mut rng = thread_rng();
+            let generator = <E as PrimeCurveAffine>::generator();
+            let point2 = (generator * field::small_constant::<E::ScalarExt>(7)).to_affine();
+            let scalar1 = E::ScalarExt::random(&mut rng);
+            let scalar2 = E::ScalarExt::random(&mut rng);
+
+            // expected = scalar1*generator + scalar2*point2, computed independently of `mul2`
+            let expected = (generator * scalar1 + point2 * scalar2).to_affine();
+
+            layouter.assign_region(
+                || "mul2",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let scalar1_assigned = scalar_chip.assign_constant(&mut region, scalar1, offset)?;
+                    let scalar2_assigned = scalar_chip.assign_constant(&mut region, scalar2, offset)?;
+                    let point2_assigned = ecc_chip.assign_point(&mut region, Some(point2), offset)?;
+
+                    let result = ecc_chip.mul2(&mut region, generator, &scalar1_assigned, &point2_assigned, &scalar2_assigned, 2, offset)?;
+                    let expected_assigned = ecc_chip.assign_point(&mut region, Some(expected), offset)?;
+
+                    let base_chip = ecc_chip.base_field_chip();
+                    base_chip.assert_strict_equal(&mut region, &result.x, &expected_assigned.x, offset)?;
+                    base_chip.assert_strict_equal(&mut region, &result.y, &expected_assigned.y, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    #[test]
+    fn mul2_matches_independent_double_scalar_mul() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::{Fq as Field, G1Affine as Curve};
+            } else {
+                use halo2::pasta::{EqAffine as Curve, Fp as Field};
+            }
+        }
+
+        let k = 14;
+        let circuit = TestCircuitMul2::<Curve, Field>::default();
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitMsm<E: CurveAffine, N: FieldExt> {
+        _marker: PhantomData<(E, N)>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitMsm<E, N> {
+        type Config = TestMul2Config;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestMul2Config::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            let mut rng = thread_rng();
+            let generator = <E as PrimeCurveAffine>::generator();
+            let point2 = (generator * field::small_constant::<E::ScalarExt>(7)).to_affine();
+            let point3 = (generator * field::small_constant::<E::ScalarExt>(11)).to_affine();
+            let scalars: Vec<E::ScalarExt> = (0..3).map(|_| E::ScalarExt::random(&mut rng)).collect();
+            let bases = [generator, point2, point3];
+
+            let mut expected_proj = bases[0] * scalars[0];
+            for (base, scalar) in bases.iter().zip(scalars.iter()).skip(1) {
+                expected_proj = expected_proj + *base * *scalar;
+            }
+            let expected = expected_proj.to_affine();
+
+            layouter.assign_region(
+                || "msm",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let mut points = Vec::with_capacity(bases.len());
+                    let mut assigned_scalars = Vec::with_capacity(scalars.len());
+                    for (base, scalar) in bases.iter().zip(scalars.iter()) {
+                        points.push(ecc_chip.assign_point(&mut region, Some(*base), offset)?);
+                        assigned_scalars.push(scalar_chip.assign_constant(&mut region, *scalar, offset)?);
+                    }
+
+                    let result = ecc_chip.msm(&mut region, &points, &assigned_scalars, 2, offset)?;
+                    let expected_assigned = ecc_chip.assign_point(&mut region, Some(expected), offset)?;
+
+                    let base_chip = ecc_chip.base_field_chip();
+                    base_chip.assert_strict_equal(&mut region, &result.x, &expected_assigned.x, offset)?;
+                    base_chip.assert_strict_equal(&mut region, &result.y, &expected_assigned.y, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    #[test]
+    fn msm_matches_independent_scalar_mul_sum() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::{Fq as Field, G1Affine as Curve};
+            } else {
+                use halo2::pasta::{EqAffine as Curve, Fp as Field};
+            }
+        }
+
+        let k = 14;
+        let circuit = TestCircuitMsm::<Curve, Field>::default();
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone)]
+    struct TestCircuitPointFromX<E: CurveAffine, N: FieldExt> {
+        _marker: PhantomData<(E, N)>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitPointFromX<E, N> {
+        type Config = TestMul2Config;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestMul2Config::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let base_chip = ecc_chip.base_field_chip();
+
+            let mut rng = thread_rng();
+            let generator = <E as PrimeCurveAffine>::generator();
+            let point = (generator * E::ScalarExt::random(&mut rng)).to_affine();
+            let x = *point.coordinates().unwrap().x();
+
+            layouter.assign_region(
+                || "point_from_x",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let x_assigned = base_chip.assign_constant(&mut region, x, offset)?;
+                    let recovered = ecc_chip.assign_point_from_x(&mut region, &x_assigned, offset)?;
+
+                    // recovered must actually satisfy the curve equation, whichever of the
+                    // two square roots it picked.
+                    let y2 = base_chip.mul(&mut region, &recovered.y, &recovered.y, offset)?;
+                    let x2 = base_chip.mul(&mut region, &recovered.x, &recovered.x, offset)?;
+                    let x3 = base_chip.mul(&mut region, &x2, &recovered.x, offset)?;
+                    let b = base_chip.assign_constant(&mut region, field::small_constant::<E::Base>(CURVE_B), offset)?;
+                    let rhs = base_chip.add(&mut region, &x3, &b, offset)?;
+                    base_chip.assert_strict_equal(&mut region, &y2, &rhs, offset)?;
+
+                    // adding the recovered point to its own negation must collapse to (0, 0).
+                    let neg_y = base_chip.neg(&mut region, &recovered.y, offset)?;
+                    let negated = AssignedPoint::new(recovered.x.clone(), neg_y);
+                    let sum = ecc_chip.add(&mut region, &recovered, &negated, offset)?;
+                    ecc_chip.assert_is_identity(&mut region, &sum, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    #[test]
+    fn assign_point_from_x_round_trips_and_cancels_with_negation() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::{Fq as Field, G1Affine as Curve};
+            } else {
+                use halo2::pasta::{EqAffine as Curve, Fp as Field};
+            }
+        }
+
+        let k = 14;
+        let circuit = TestCircuitPointFromX::<Curve, Field>::default();
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "kzg")] {
+            use halo2::pairing::bn256::Fq as Fp;
+        } else {
+            use halo2::pasta::Fp;
+        }
+    }
+
+    fn find_point() -> (Fp, Fp) {
+        let b = field::small_constant::<Fp>(CURVE_B);
+        let mut x = Fp::zero();
+        loop {
+            let rhs = x.square() * x + b;
+            if let Some(y) = Option::<Fp>::from(rhs.sqrt()) {
+                if !is_identity((x, y)) {
+                    return (x, y);
+                }
+            }
+            x += Fp::one();
+        }
+    }
+
+    #[test]
+    fn double_clear_matches_add_clear_with_self() {
+        let p = find_point();
+        assert_eq!(double_clear(p), add_clear(p, p));
+    }
+
+    #[test]
+    fn mul_clear_matches_repeated_addition() {
+        let p = find_point();
+        let mut acc = (Fp::zero(), Fp::zero());
+        for _ in 0..5 {
+            acc = add_clear(acc, p);
+        }
+        assert_eq!(mul_clear(p, field::small_constant::<Fp>(5)), acc);
+    }
+}
@@ -0,0 +1,46 @@
+pub mod general_ecc;
+
+use halo2::arithmetic::FieldExt;
+use halo2arith::halo2;
+use halo2arith::main_gate::five::main_gate::MainGateConfig;
+use halo2arith::main_gate::five::range::RangeConfig;
+
+use crate::circuit::integer::AssignedInteger;
+
+#[derive(Clone, Debug)]
+pub struct EccConfig {
+    pub(crate) range_config: RangeConfig,
+    pub(crate) main_gate_config: MainGateConfig,
+}
+
+impl EccConfig {
+    pub fn new(range_config: RangeConfig, main_gate_config: MainGateConfig) -> Self {
+        Self {
+            range_config,
+            main_gate_config,
+        }
+    }
+}
+
+/// An elliptic-curve point assigned into a region. The identity is represented by the
+/// sentinel coordinates `(0, 0)`, which never lies on any of the short-Weierstrass curves
+/// this chip supports (they all have a nonzero `b`).
+#[derive(Clone, Debug)]
+pub struct AssignedPoint<N: FieldExt> {
+    pub(crate) x: AssignedInteger<N>,
+    pub(crate) y: AssignedInteger<N>,
+}
+
+impl<N: FieldExt> AssignedPoint<N> {
+    pub fn new(x: AssignedInteger<N>, y: AssignedInteger<N>) -> Self {
+        Self { x, y }
+    }
+
+    pub fn get_x(&self) -> AssignedInteger<N> {
+        self.x.clone()
+    }
+
+    pub fn get_y(&self) -> AssignedInteger<N> {
+        self.y.clone()
+    }
+}
@@ -6,10 +6,118 @@ use halo2::arithmetic::{CurveAffine, FieldExt};
 use halo2::circuit::Region;
 use halo2::plonk::Error;
 use halo2arith::halo2;
-use halo2arith::main_gate::five::main_gate::MainGateConfig;
+use halo2arith::main_gate::five::main_gate::{AssignedCondition, AssignedValue, MainGate, MainGateConfig, MainGateInstructions};
 use halo2arith::main_gate::five::range::RangeConfig;
 
 use super::integer::{IntegerChip, IntegerConfig};
+use self::sponge::SpongeHasherChip;
+
+/// A Poseidon sponge over a `WIDTH`-element state, parameterized by its round constants
+/// (`WIDTH` per round) and its `WIDTH x WIDTH` MDS matrix. Absorbs an in-circuit message and
+/// squeezes a single field element out of it; reused anywhere a message needs to be
+/// committed to before it is fed into an arithmetic chip (`verify_with_hash`) or used to
+/// derive Fiat-Shamir challenges (`verify_batch`).
+mod sponge {
+    use halo2::arithmetic::FieldExt;
+    use halo2::circuit::Region;
+    use halo2::plonk::Error;
+    use halo2arith::halo2;
+    use halo2arith::main_gate::five::main_gate::{AssignedValue, MainGate, MainGateConfig, MainGateInstructions};
+
+    const WIDTH: usize = 3;
+
+    #[derive(Clone, Debug)]
+    pub struct SpongeHasherChip<N: FieldExt> {
+        main_gate_config: MainGateConfig,
+        // one `WIDTH`-element row of round constants per round
+        round_constants: Vec<[N; WIDTH]>,
+        mds: [[N; WIDTH]; WIDTH],
+    }
+
+    impl<N: FieldExt> SpongeHasherChip<N> {
+        /// `round_constants` must supply `WIDTH` constants per round (flattened, round-major);
+        /// `mds` must be `WIDTH` rows of `WIDTH` entries each (also flattened, row-major).
+        pub fn new(main_gate_config: MainGateConfig, round_constants: Vec<N>, mds: Vec<Vec<N>>) -> Self {
+            assert_eq!(round_constants.len() % WIDTH, 0);
+            let round_constants = round_constants.chunks(WIDTH).map(|round| [round[0], round[1], round[2]]).collect();
+
+            assert_eq!(mds.len(), WIDTH);
+            let mut mds_rows = [[N::zero(); WIDTH]; WIDTH];
+            for (row, src) in mds_rows.iter_mut().zip(mds.iter()) {
+                assert_eq!(src.len(), WIDTH);
+                row.copy_from_slice(src);
+            }
+
+            Self {
+                main_gate_config,
+                round_constants,
+                mds: mds_rows,
+            }
+        }
+
+        fn main_gate(&self) -> MainGate<N> {
+            MainGate::new(self.main_gate_config.clone())
+        }
+
+        /// One Poseidon round: add the round's constants, apply the S-box `x^5` to every
+        /// state element (full round — this sponge only runs full rounds, skipping the
+        /// partial-round optimization a production Poseidon instance would use), then mix
+        /// the whole state through every entry of the MDS matrix.
+        fn round(&self, region: &mut Region<'_, N>, main_gate: &MainGate<N>, state: [AssignedValue<N>; WIDTH], round_constants: &[N; WIDTH], offset: &mut usize) -> Result<[AssignedValue<N>; WIDTH], Error> {
+            let mut added = Vec::with_capacity(WIDTH);
+            for (s, rc) in state.iter().zip(round_constants.iter()) {
+                let rc = main_gate.assign_constant(region, *rc, offset)?;
+                added.push(main_gate.add(region, s, &rc, offset)?);
+            }
+
+            let mut boxed = Vec::with_capacity(WIDTH);
+            for s in added.iter() {
+                let s2 = main_gate.mul(region, s, s, offset)?;
+                let s4 = main_gate.mul(region, &s2, &s2, offset)?;
+                boxed.push(main_gate.mul(region, &s4, s, offset)?);
+            }
+
+            let mut mixed = Vec::with_capacity(WIDTH);
+            for mds_row in self.mds.iter() {
+                let mut acc: Option<AssignedValue<N>> = None;
+                for (coeff, s) in mds_row.iter().zip(boxed.iter()) {
+                    let coeff = main_gate.assign_constant(region, *coeff, offset)?;
+                    let term = main_gate.mul(region, &coeff, s, offset)?;
+                    acc = Some(match acc {
+                        Some(acc) => main_gate.add(region, &acc, &term, offset)?,
+                        None => term,
+                    });
+                }
+                mixed.push(acc.unwrap());
+            }
+
+            Ok([mixed[0].clone(), mixed[1].clone(), mixed[2].clone()])
+        }
+
+        /// Absorbs `limbs` `WIDTH - 1` at a time into the rate portion of the state (the
+        /// first element is kept as a capacity element, never directly overwritten by input),
+        /// running the full Poseidon permutation between absorptions, then returns the first
+        /// state element as the squeezed digest.
+        pub fn hash(&self, region: &mut Region<'_, N>, limbs: &[AssignedValue<N>], offset: &mut usize) -> Result<AssignedValue<N>, Error> {
+            let main_gate = self.main_gate();
+            assert!(!limbs.is_empty());
+
+            let zero = main_gate.assign_constant(region, N::zero(), offset)?;
+            let mut state = [zero.clone(), zero.clone(), zero];
+
+            for chunk in limbs.chunks(WIDTH - 1) {
+                for (i, limb) in chunk.iter().enumerate() {
+                    state[i + 1] = main_gate.add(region, &state[i + 1], limb, offset)?;
+                }
+                for round_constants in self.round_constants.iter() {
+                    state = self.round(region, &main_gate, state, round_constants, offset)?;
+                }
+            }
+
+            Ok(state[0].clone())
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct EcdsaConfig {
@@ -65,13 +173,58 @@ impl<E: CurveAffine, N: FieldExt> EcdsaChip<E, N> {
     }
 }
 
-impl<E: CurveAffine, N: FieldExt> EcdsaChip<E, N> {
+/// Lets a circuit verify signatures against an abstract chip instead of a concrete one, so it
+/// can be rebuilt against a different signature backend without rewriting callers.
+pub trait EcdsaInstructions<E: CurveAffine, N: FieldExt> {
+    type AssignedSig;
+    type AssignedPubKey;
+
+    fn assign_signature(
+        &self,
+        region: &mut Region<'_, N>,
+        signature: EcdsaSig<'_, E::ScalarExt, N>,
+        offset: &mut usize,
+    ) -> Result<Self::AssignedSig, Error>;
+
+    fn assign_public_key(&self, region: &mut Region<'_, N>, public_key: Option<E>, offset: &mut usize) -> Result<Self::AssignedPubKey, Error>;
+
     // https://en.wikipedia.org/wiki/Elliptic_Curve_Digital_Signature_Algorithm
     fn verify(
         &self,
         region: &mut Region<'_, N>,
-        sig: &AssignedEcdsaSig<N>,
-        pk: &AssignedPublicKey<N>,
+        sig: &Self::AssignedSig,
+        pk: &Self::AssignedPubKey,
+        msg_hash: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error>;
+}
+
+impl<E: CurveAffine, N: FieldExt> EcdsaInstructions<E, N> for EcdsaChip<E, N> {
+    type AssignedSig = AssignedEcdsaSig<N>;
+    type AssignedPubKey = AssignedPublicKey<N>;
+
+    fn assign_signature(
+        &self,
+        region: &mut Region<'_, N>,
+        signature: EcdsaSig<'_, E::ScalarExt, N>,
+        offset: &mut usize,
+    ) -> Result<Self::AssignedSig, Error> {
+        let scalar_chip = self.scalar_field_chip();
+        let r = scalar_chip.assign_integer(region, signature.r.into(), offset)?;
+        let s = scalar_chip.assign_integer(region, signature.s.into(), offset)?;
+        Ok(AssignedEcdsaSig { r, s })
+    }
+
+    fn assign_public_key(&self, region: &mut Region<'_, N>, public_key: Option<E>, offset: &mut usize) -> Result<Self::AssignedPubKey, Error> {
+        let point = self.ecc_chip().assign_point(region, public_key, offset)?;
+        Ok(AssignedPublicKey { point })
+    }
+
+    fn verify(
+        &self,
+        region: &mut Region<'_, N>,
+        sig: &Self::AssignedSig,
+        pk: &Self::AssignedPubKey,
         msg_hash: &AssignedInteger<N>,
         offset: &mut usize,
     ) -> Result<(), Error> {
@@ -95,10 +248,10 @@ impl<E: CurveAffine, N: FieldExt> EcdsaChip<E, N> {
         let u2 = scalar_chip.mul(region, &sig.r, &s_inv, offset)?;
 
         // 5. compute Q = u1*G + u2*pk
-        let e_gen = ecc_chip.assign_point(region, Some(E::generator()), offset)?;
-        let g1 = ecc_chip.mul(region, &e_gen, &u1, 2, offset)?;
-        let g2 = ecc_chip.mul(region, &pk.point, &u2, 2, offset)?;
-        let q = ecc_chip.add(region, &g1, &g2, offset)?;
+        // instead of two independent `mul`s (each paying for its own doublings) followed by
+        // an `add`, run both off one shared ladder: `u2`'s bits drive one doubling per bit, and
+        // `u1*G` is added in once per 2-bit window from a fixed table, with no doublings of its own.
+        let q = ecc_chip.mul2(region, E::generator(), &u1, &pk.point, &u2, 2, offset)?;
 
         // 6. reduce q_x in E::ScalarExt
         // assuming E::Base/E::ScalarExt have the same number of limbs
@@ -113,13 +266,220 @@ impl<E: CurveAffine, N: FieldExt> EcdsaChip<E, N> {
     }
 }
 
+impl<E: CurveAffine, N: FieldExt> EcdsaChip<E, N> {
+    /// Verifies `sigs.len()` signatures with one combined group equation: instead of
+    /// checking each `u1_i*G + u2_i*pk_i - R_i == O` independently, every signature is weighted
+    /// by a transcript-derived challenge `rho_i` and the whole batch is checked as a single
+    /// multi-scalar-multiplication `sum(rho_i * (u1_i*G + u2_i*pk_i - R_i)) == O`. That `msm`
+    /// runs a full scalar multiplication for each of the `3m` terms, so the elliptic-curve work
+    /// still grows linearly in `m`. `rho_i = seed^(i+1)`
+    /// (`rho_0 = seed`, `rho_{i+1} = rho_i * seed`) are plain sequential powers of a single
+    /// transcript-derived `seed`, not repeated squarings — squaring would give `seed^(2^i)`,
+    /// whose exponents can collide mod the scalar field's order as `i` grows, undermining the
+    /// Schwartz-Zippel argument the batching soundness relies on. `seed` is squeezed from
+    /// `hasher`, seeded with every signature's `(r, s, msg_hash, pk, R)` before any `rho_i` is
+    /// used, so a prover committed to those values cannot learn `rho_i` beforehand and pick a
+    /// forged term to cancel out of the combined sum — binding `pk_i` and `R_i` themselves (not
+    /// just `r`/`s`/`msg_hash`) matters because those two are exactly what the per-signature
+    /// term `u1_i*G + u2_i*pk_i - R_i` depends on. `R_i` is witnessed from `sig.r` with its `y`-
+    /// parity pinned by `r_parities[i]` (same mechanism as `recover`'s `recovery_id`) and is
+    /// range-constrained to reduce back to `r_i`, so neither the square root nor a forged `R_i`
+    /// can slip into the combined sum or the transcript.
+    pub fn verify_batch(
+        &self,
+        region: &mut Region<'_, N>,
+        sigs: &[AssignedEcdsaSig<N>],
+        pks: &[AssignedPublicKey<N>],
+        msg_hashes: &[AssignedInteger<N>],
+        r_parities: &[AssignedCondition<N>],
+        hasher: &SpongeHasherChip<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        assert_eq!(sigs.len(), pks.len());
+        assert_eq!(sigs.len(), msg_hashes.len());
+        assert_eq!(sigs.len(), r_parities.len());
+        let m = sigs.len();
+
+        let ecc_chip = self.ecc_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+        let base_chip = ecc_chip.base_field_chip();
+
+        // reconstruct every R_i up front (instead of alongside u1/u2 below) so it can be
+        // folded into the transcript before `rho` is squeezed.
+        let mut r_points = Vec::with_capacity(m);
+        for i in 0..m {
+            let sig = &sigs[i];
+
+            // R_i is witnessed from r_i with its y-parity pinned by `r_parities[i]` (instead
+            // of `assign_point_from_x`'s arbitrary root) and must reduce back to r_i, the same
+            // way `verify` checks `Q.x == r` but against a witness instead of a recomputed sum.
+            let r_point = ecc_chip.assign_point_from_x_with_parity(region, &sig.r, &r_parities[i], offset)?;
+            let r_x_reduced_in_q = base_chip.reduce(region, &r_point.get_x(), offset)?;
+            let r_x_reduced_in_r = scalar_chip.reduce(region, &r_x_reduced_in_q, offset)?;
+            scalar_chip.assert_strict_equal(region, &r_x_reduced_in_r, &sig.r, offset)?;
+            r_points.push(r_point);
+        }
+
+        // seed the transcript with every signature's (r, s, msg_hash, pk, R) before squeezing
+        // `rho`.
+        let mut transcript = Vec::with_capacity(7 * m);
+        for i in 0..m {
+            transcript.push(sigs[i].r.native().clone());
+            transcript.push(sigs[i].s.native().clone());
+            transcript.push(msg_hashes[i].native().clone());
+            transcript.push(pks[i].point.get_x().native().clone());
+            transcript.push(pks[i].point.get_y().native().clone());
+            transcript.push(r_points[i].get_x().native().clone());
+            transcript.push(r_points[i].get_y().native().clone());
+        }
+        let seed = hasher.hash(region, &transcript, offset)?;
+        let seed = scalar_chip.assign_integer_from_native(region, &seed, offset)?;
+
+        let mut points = Vec::with_capacity(3 * m);
+        let mut scalars = Vec::with_capacity(3 * m);
+        let mut rho = seed.clone();
+        for i in 0..m {
+            let sig = &sigs[i];
+            let pk = &pks[i];
+            let msg_hash = &msg_hashes[i];
+
+            scalar_chip.assert_not_zero(region, &sig.r, offset)?;
+            scalar_chip.assert_not_zero(region, &sig.s, offset)?;
+
+            let (s_inv, _) = scalar_chip.invert(region, &sig.s, offset)?;
+            let u1 = scalar_chip.mul(region, msg_hash, &s_inv, offset)?;
+            let u2 = scalar_chip.mul(region, &sig.r, &s_inv, offset)?;
+
+            let rho_u1 = scalar_chip.mul(region, &rho, &u1, offset)?;
+            let rho_u2 = scalar_chip.mul(region, &rho, &u2, offset)?;
+            let rho_neg = scalar_chip.neg(region, &rho, offset)?;
+
+            points.push(ecc_chip.assign_point(region, Some(E::generator()), offset)?);
+            scalars.push(rho_u1);
+            points.push(pk.point.clone());
+            scalars.push(rho_u2);
+            points.push(r_points[i].clone());
+            scalars.push(rho_neg);
+
+            if i + 1 < m {
+                rho = scalar_chip.mul(region, &rho, &seed, offset)?;
+            }
+        }
+
+        // fold the whole batch into one multi-scalar-multiplication and assert it collapses
+        // to the identity; `msm` still runs one full `mul` per term.
+        let combined = ecc_chip.msm(region, &points, &scalars, 2, offset)?;
+        ecc_chip.assert_is_identity(region, &combined, offset)?;
+
+        Ok(())
+    }
+
+    /// Recovers the public key from a signature and message hash, mirroring the EVM
+    /// `ecrecover` precompile: `R` is reconstructed from its x-coordinate (the curve equation
+    /// plus `recovery_id` disambiguates the two possible `y`s), then `pk = u1*G + u2*R` with
+    /// `u1 = -m*r^{-1}`, `u2 = s*r^{-1}`. `r` is `R.x mod n`, but `R.x` itself can be `r + n`
+    /// in the rare case where `r + n` is still below the base field's modulus; `is_x_overflowed`
+    /// is an explicit witnessed flag for that case, selecting between `r` and `r + n` before
+    /// point recovery runs. Unlike `verify`, this produces a witnessed output rather than
+    /// asserting an equality, so callers are expected to constrain the returned key against an
+    /// expected value.
+    pub fn recover(
+        &self,
+        region: &mut Region<'_, N>,
+        sig: &AssignedEcdsaSig<N>,
+        recovery_id: &AssignedCondition<N>,
+        is_x_overflowed: &AssignedInteger<N>,
+        msg_hash: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedPublicKey<N>, Error> {
+        let ecc_chip = self.ecc_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+
+        scalar_chip.assert_not_zero(region, &sig.r, offset)?;
+        scalar_chip.assert_not_zero(region, &sig.s, offset)?;
+
+        // `is_x_overflowed` feeds straight into `select` below, which requires its `cond` to
+        // already be boolean-constrained; `assign_bits`'s callers get that for free from the
+        // bit-decomposition itself, but a standalone witnessed flag like this one needs it
+        // spelled out explicitly: `bit * (bit - 1) == 0` rules out anything but 0/1.
+        let overflow_sq = scalar_chip.mul(region, is_x_overflowed, is_x_overflowed, offset)?;
+        scalar_chip.assert_strict_equal(region, &overflow_sq, is_x_overflowed, offset)?;
+
+        // 1. reconstruct R from its x-coordinate (`r`, or `r + n` when `is_x_overflowed`),
+        //    with `recovery_id` selecting the odd/even y-coordinate
+        // `n` is assigned as a native constant: as an `E::ScalarExt` value it would be 0.
+        let n = ecc_chip.assign_native_constant(region, scalar_chip.rns().wrong_modulus_in_native(), offset)?;
+        let r_plus_n = scalar_chip.add(region, &sig.r, &n, offset)?;
+        let x = scalar_chip.select(region, is_x_overflowed, &r_plus_n, &sig.r, offset)?;
+
+        let r_point = ecc_chip.assign_point_from_x_with_parity(region, &x, recovery_id, offset)?;
+        ecc_chip.assert_is_on_curve(region, &r_point, offset)?;
+        ecc_chip.assert_not_identity(region, &r_point, offset)?;
+
+        // 2. u1 = -m * r^{-1} (mod n), u2 = s * r^{-1} (mod n)
+        let (r_inv, _) = scalar_chip.invert(region, &sig.r, offset)?;
+        let u1 = scalar_chip.mul(region, msg_hash, &r_inv, offset)?;
+        let u1 = scalar_chip.neg(region, &u1, offset)?;
+        let u2 = scalar_chip.mul(region, &sig.s, &r_inv, offset)?;
+
+        // 3. pk = u1*G + u2*R
+        let point = ecc_chip.mul2(region, E::generator(), &u1, &r_point, &u2, 2, offset)?;
+
+        Ok(AssignedPublicKey { point })
+    }
+
+    /// Same as `verify`, but takes the raw message instead of a pre-reduced `msg_hash`, so the
+    /// hash itself is proven in-circuit rather than trusted from the caller: `message` is
+    /// absorbed by a Poseidon sponge, and the single squeezed native-field element is fed into
+    /// the existing `verify` path unchanged as `msg_hash`; it is not reduced modulo the scalar
+    /// field's order first.
+    pub fn verify_with_hash(
+        &self,
+        region: &mut Region<'_, N>,
+        sig: &AssignedEcdsaSig<N>,
+        pk: &AssignedPublicKey<N>,
+        hasher: &SpongeHasherChip<N>,
+        message: &[AssignedValue<N>],
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let ecc_chip = self.ecc_chip();
+        let scalar_chip = ecc_chip.scalar_field_chip();
+
+        let digest = hasher.hash(region, message, offset)?;
+        let msg_hash = scalar_chip.assign_integer_from_native(region, &digest, offset)?;
+
+        self.verify(region, sig, pk, &msg_hash, offset)
+    }
+
+    /// Same as `verify`, but additionally rejects malleable signatures: plain ECDSA accepts
+    /// both `s` and `n - s` for the same message, so this constrains `s <= (n-1)/2`, the
+    /// canonical low-`s` form required in EVM/consensus contexts. `(n-1)/2` is itself a valid
+    /// canonical `s`, so the comparison must accept equality, not just `s < (n-1)/2`.
+    pub fn verify_strict(
+        &self,
+        region: &mut Region<'_, N>,
+        sig: &AssignedEcdsaSig<N>,
+        pk: &AssignedPublicKey<N>,
+        msg_hash: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let scalar_chip = self.scalar_field_chip();
+
+        let half_n = scalar_chip.rns().half_modulus();
+        let half_n = scalar_chip.assign_constant(region, half_n, offset)?;
+        scalar_chip.assert_smaller_than_or_equal(region, &sig.s, &half_n, offset)?;
+
+        self.verify(region, sig, pk, msg_hash, offset)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::marker::PhantomData;
 
     use crate::circuit::ecc::general_ecc::GeneralEccChip;
     use crate::circuit::ecc::EccConfig;
-    use crate::circuit::ecdsa::{AssignedEcdsaSig, AssignedPublicKey, EcdsaChip, EcdsaConfig};
+    use crate::circuit::ecdsa::{AssignedEcdsaSig, AssignedPublicKey, EcdsaChip, EcdsaConfig, EcdsaInstructions, EcdsaSig};
     use crate::circuit::integer::{IntegerConfig, IntegerInstructions};
     use crate::NUMBER_OF_LOOKUP_LIMBS;
     use group::ff::Field;
@@ -337,4 +697,531 @@ mod tests {
 
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    /// Mirrors `sponge::SpongeHasherChip::hash` in plain field arithmetic (add round
+    /// constants, `x^5` S-box, full `3x3` MDS mix, rate `WIDTH - 1 = 2`) so the circuit's
+    /// output can be checked against an independently computed expected digest instead of
+    /// only checking the proof is internally consistent.
+    fn poseidon_hash_clear<N: FieldExt>(limbs: &[N], round_constants: &[N], mds: &[Vec<N>]) -> N {
+        let rounds: Vec<[N; 3]> = round_constants.chunks(3).map(|c| [c[0], c[1], c[2]]).collect();
+        let mds: [[N; 3]; 3] = [
+            [mds[0][0], mds[0][1], mds[0][2]],
+            [mds[1][0], mds[1][1], mds[1][2]],
+            [mds[2][0], mds[2][1], mds[2][2]],
+        ];
+
+        let mut state = [N::zero(); 3];
+        for chunk in limbs.chunks(2) {
+            for (i, limb) in chunk.iter().enumerate() {
+                state[i + 1] += *limb;
+            }
+            for rc in rounds.iter() {
+                let added: Vec<N> = state.iter().zip(rc.iter()).map(|(s, r)| *s + *r).collect();
+                let boxed: Vec<N> = added.iter().map(|s| s.square().square() * s).collect();
+                let mut mixed = [N::zero(); 3];
+                for (row_i, row) in mds.iter().enumerate() {
+                    mixed[row_i] = row.iter().zip(boxed.iter()).map(|(c, b)| *c * *b).fold(N::zero(), |acc, t| acc + t);
+                }
+                state = mixed;
+            }
+        }
+        state[0]
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitPoseidon<N: FieldExt> {
+        limbs: Vec<N>,
+        expected: N,
+    }
+
+    impl<N: FieldExt> Circuit<N> for TestCircuitPoseidon<N> {
+        type Config = MainGateConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                limbs: vec![N::zero(); self.limbs.len()],
+                expected: N::zero(),
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            MainGate::<N>::configure(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.clone());
+            let round_constants: Vec<N> = (1..=6u64).map(|n| crate::field::small_constant(n)).collect();
+            let mds: Vec<Vec<N>> = vec![
+                vec![crate::field::small_constant(2), crate::field::small_constant(3), crate::field::small_constant(5)],
+                vec![crate::field::small_constant(7), crate::field::small_constant(11), crate::field::small_constant(13)],
+                vec![crate::field::small_constant(17), crate::field::small_constant(19), crate::field::small_constant(23)],
+            ];
+            let hasher = super::sponge::SpongeHasherChip::new(config, round_constants, mds);
+
+            layouter.assign_region(
+                || "poseidon",
+                |mut region| {
+                    let offset = &mut 0;
+                    let mut assigned_limbs = Vec::with_capacity(self.limbs.len());
+                    for limb in &self.limbs {
+                        assigned_limbs.push(main_gate.assign_value(&mut region, Some(*limb), offset)?);
+                    }
+                    let digest = hasher.hash(&mut region, &assigned_limbs, offset)?;
+                    let expected = main_gate.assign_constant(&mut region, self.expected, offset)?;
+                    main_gate.assert_equal(&mut region, &digest, &expected, offset)
+                },
+            )
+        }
+    }
+
+    #[derive(Clone)]
+    struct SigWitness<E: CurveAffine> {
+        pk: E,
+        r: E::ScalarExt,
+        s: E::ScalarExt,
+        m_hash: E::ScalarExt,
+        r_is_odd: bool,
+    }
+
+    fn random_sig<E: CurveAffine, N: FieldExt>(rng: &mut impl rand::RngCore) -> SigWitness<E> {
+        let generator = <E as PrimeCurveAffine>::generator();
+        let sk = E::ScalarExt::random(&mut *rng);
+        let pk = (generator * sk).to_affine();
+        let m_hash = E::ScalarExt::random(&mut *rng);
+        let nonce = E::ScalarExt::random(&mut *rng);
+        let nonce_inv = nonce.invert().unwrap();
+        let r_point = (generator * nonce).to_affine();
+        let r_coords = r_point.coordinates().unwrap();
+
+        let mut x_repr = [0u8; 32];
+        x_repr.copy_from_slice(r_coords.x().to_repr().as_ref());
+        let mut x_bytes = [0u8; 64];
+        x_bytes[..32].copy_from_slice(&x_repr);
+        let r = E::ScalarExt::from_bytes_wide(&x_bytes);
+        let s = nonce_inv * (m_hash + r * sk);
+
+        let r_y_in_n: N = crate::field::convert::<E::Base, N>(*r_coords.y());
+        let r_is_odd = r_y_in_n.to_repr().as_ref()[0] & 1 == 1;
+
+        SigWitness { pk, r, s, m_hash, r_is_odd }
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitEcdsaVerifyBatch<E: CurveAffine, N: FieldExt> {
+        sigs: Vec<SigWitness<E>>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitEcdsaVerifyBatch<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                sigs: self.sigs.clone(),
+                _marker: PhantomData,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+
+            let round_constants: Vec<N> = (1..=6u64).map(|n| crate::field::small_constant(n)).collect();
+            let mds: Vec<Vec<N>> = vec![
+                vec![crate::field::small_constant(2), crate::field::small_constant(3), crate::field::small_constant(5)],
+                vec![crate::field::small_constant(7), crate::field::small_constant(11), crate::field::small_constant(13)],
+                vec![crate::field::small_constant(17), crate::field::small_constant(19), crate::field::small_constant(23)],
+            ];
+            let hasher = super::sponge::SpongeHasherChip::new(config.main_gate_config.clone(), round_constants, mds);
+
+            layouter.assign_region(
+                || "verify_batch",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let mut assigned_sigs = Vec::with_capacity(self.sigs.len());
+                    let mut assigned_pks = Vec::with_capacity(self.sigs.len());
+                    let mut assigned_hashes = Vec::with_capacity(self.sigs.len());
+                    let mut assigned_parities = Vec::with_capacity(self.sigs.len());
+
+                    for sig in &self.sigs {
+                        let r = scalar_chip.assign_constant(&mut region, sig.r, offset)?;
+                        let s = scalar_chip.assign_constant(&mut region, sig.s, offset)?;
+                        assigned_sigs.push(AssignedEcdsaSig { r, s });
+
+                        let point = ecc_chip.assign_point(&mut region, Some(sig.pk), offset)?;
+                        assigned_pks.push(AssignedPublicKey { point });
+
+                        assigned_hashes.push(scalar_chip.assign_constant(&mut region, sig.m_hash, offset)?);
+
+                        // An `AssignedCondition` is produced here by `invert`'s is-zero flag,
+                        // the only primitive this chip has for witnessing one directly: the
+                        // flag is `true` exactly when the inverted value is `0`.
+                        let flag_seed = if sig.r_is_odd { E::ScalarExt::zero() } else { E::ScalarExt::one() };
+                        let flag_witness = scalar_chip.assign_constant(&mut region, flag_seed, offset)?;
+                        let (_, parity) = scalar_chip.invert(&mut region, &flag_witness, offset)?;
+                        assigned_parities.push(parity);
+                    }
+
+                    ecdsa_chip.verify_batch(&mut region, &assigned_sigs, &assigned_pks, &assigned_hashes, &assigned_parities, &hasher, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitRecover<E: CurveAffine, N: FieldExt> {
+        sig: SigWitness<E>,
+        overflow_flag: E::ScalarExt,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitRecover<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                sig: self.sig.clone(),
+                overflow_flag: self.overflow_flag,
+                _marker: PhantomData,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+
+            layouter.assign_region(
+                || "recover",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let r = scalar_chip.assign_constant(&mut region, self.sig.r, offset)?;
+                    let s = scalar_chip.assign_constant(&mut region, self.sig.s, offset)?;
+                    let sig = AssignedEcdsaSig { r, s };
+                    let msg_hash = scalar_chip.assign_constant(&mut region, self.sig.m_hash, offset)?;
+
+                    let parity_seed = if self.sig.r_is_odd { E::ScalarExt::zero() } else { E::ScalarExt::one() };
+                    let parity_witness = scalar_chip.assign_constant(&mut region, parity_seed, offset)?;
+                    let (_, recovery_id) = scalar_chip.invert(&mut region, &parity_witness, offset)?;
+
+                    let overflow_flag = scalar_chip.assign_constant(&mut region, self.overflow_flag, offset)?;
+
+                    let recovered = ecdsa_chip.recover(&mut region, &sig, &recovery_id, &overflow_flag, &msg_hash, offset)?;
+                    let expected = ecc_chip.assign_point(&mut region, Some(self.sig.pk), offset)?;
+
+                    let base_chip = ecc_chip.base_field_chip();
+                    base_chip.assert_strict_equal(&mut region, &recovered.point.x, &expected.x, offset)?;
+                    base_chip.assert_strict_equal(&mut region, &recovered.point.y, &expected.y, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    /// Generic over `EcdsaInstructions` rather than the concrete `EcdsaChip`, exercising the
+    /// trait abstraction itself rather than just the one chip that happens to implement it.
+    fn verify_via_trait<E, N, C>(
+        chip: &C,
+        region: &mut Region<'_, N>,
+        sig: &C::AssignedSig,
+        pk: &C::AssignedPubKey,
+        msg_hash: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error>
+    where
+        E: CurveAffine,
+        N: FieldExt,
+        C: EcdsaInstructions<E, N>,
+    {
+        chip.verify(region, sig, pk, msg_hash, offset)
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitVerifyViaTrait<E: CurveAffine, N: FieldExt> {
+        sig: SigWitness<E>,
+        _marker: PhantomData<N>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitVerifyViaTrait<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                sig: self.sig.clone(),
+                _marker: PhantomData,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let ecdsa_chip = EcdsaChip::new(ecc_chip.clone());
+
+            layouter.assign_region(
+                || "verify_via_trait",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let sig = ecdsa_chip.assign_signature(
+                        &mut region,
+                        EcdsaSig {
+                            r: ecc_chip.rns_scalar().new(self.sig.r),
+                            s: ecc_chip.rns_scalar().new(self.sig.s),
+                        },
+                        offset,
+                    )?;
+                    let pk = ecdsa_chip.assign_public_key(&mut region, Some(self.sig.pk), offset)?;
+                    let msg_hash = ecdsa_chip.scalar_field_chip().assign_constant(&mut region, self.sig.m_hash, offset)?;
+
+                    verify_via_trait::<E, N, EcdsaChip<E, N>>(&ecdsa_chip, &mut region, &sig, &pk, &msg_hash, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    #[test]
+    fn verify_is_usable_through_the_ecdsa_instructions_trait() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+                use halo2::pairing::bn256::G1Affine as Curve;
+            } else {
+                use halo2::pasta::EqAffine as Curve;
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let mut rng = thread_rng();
+        let sig = random_sig::<Curve, Field>(&mut rng);
+        let circuit = TestCircuitVerifyViaTrait::<Curve, Field> { sig, _marker: PhantomData };
+
+        let k = 20;
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Clone)]
+    struct TestCircuitLowSBoundary<E: CurveAffine, N: FieldExt> {
+        // `s` is assigned as `half_n + delta`; `delta == 0` is the canonical boundary and
+        // must be accepted, `delta == 1` must be rejected.
+        delta: u64,
+        _marker: PhantomData<(E, N)>,
+    }
+
+    impl<E: CurveAffine, N: FieldExt> Circuit<N> for TestCircuitLowSBoundary<E, N> {
+        type Config = TestCircuitEcdsaVerifyConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitEcdsaVerifyConfig::new::<E, N>(meta)
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
+            let ecc_chip = GeneralEccChip::<E, N>::new(config.ecc_chip_config(), BIT_LEN_LIMB);
+            let scalar_chip = ecc_chip.scalar_field_chip();
+
+            let half_n = scalar_chip.rns().half_modulus();
+            let s = half_n + crate::field::small_constant::<E::ScalarExt>(self.delta);
+
+            layouter.assign_region(
+                || "low_s_boundary",
+                |mut region| {
+                    let offset = &mut 0;
+                    let half_n_assigned = scalar_chip.assign_constant(&mut region, half_n, offset)?;
+                    let s_assigned = scalar_chip.assign_constant(&mut region, s, offset)?;
+                    scalar_chip.assert_smaller_than_or_equal(&mut region, &s_assigned, &half_n_assigned, offset)
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    }
+
+    #[test]
+    fn low_s_bound_check_accepts_the_canonical_boundary() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+                use halo2::pairing::bn256::G1Affine as Curve;
+            } else {
+                use halo2::pasta::EqAffine as Curve;
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let k = 14;
+        let circuit = TestCircuitLowSBoundary::<Curve, Field> { delta: 0, _marker: PhantomData };
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn low_s_bound_check_rejects_one_above_the_boundary() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+                use halo2::pairing::bn256::G1Affine as Curve;
+            } else {
+                use halo2::pasta::EqAffine as Curve;
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let k = 14;
+        let circuit = TestCircuitLowSBoundary::<Curve, Field> { delta: 1, _marker: PhantomData };
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn recover_reconstructs_the_signing_public_key() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+                use halo2::pairing::bn256::G1Affine as Curve;
+            } else {
+                use halo2::pasta::EqAffine as Curve;
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let mut rng = thread_rng();
+        let sig = random_sig::<Curve, Field>(&mut rng);
+        let circuit = TestCircuitRecover::<Curve, Field> {
+            sig,
+            overflow_flag: Field::zero(),
+            _marker: PhantomData,
+        };
+
+        let k = 20;
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn recover_rejects_a_non_boolean_overflow_flag() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+                use halo2::pairing::bn256::G1Affine as Curve;
+            } else {
+                use halo2::pasta::EqAffine as Curve;
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let mut rng = thread_rng();
+        let sig = random_sig::<Curve, Field>(&mut rng);
+        let circuit = TestCircuitRecover::<Curve, Field> {
+            sig,
+            overflow_flag: crate::field::small_constant::<Field>(2),
+            _marker: PhantomData,
+        };
+
+        let k = 20;
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn verify_batch_accepts_valid_signatures() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+                use halo2::pairing::bn256::G1Affine as Curve;
+            } else {
+                use halo2::pasta::EqAffine as Curve;
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let mut rng = thread_rng();
+        let sigs: Vec<SigWitness<Curve>> = (0..2).map(|_| random_sig::<Curve, Field>(&mut rng)).collect();
+        let circuit = TestCircuitEcdsaVerifyBatch::<Curve, Field> { sigs, _marker: PhantomData };
+
+        let k = 20;
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn poseidon_sponge_matches_plain_computation() {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "kzg")] {
+                use halo2::pairing::bn256::Fq as Field;
+            } else {
+                use halo2::pasta::Fp as Field;
+            }
+        }
+
+        let limbs: Vec<Field> = (1..=4u64).map(|n| crate::field::small_constant(n)).collect();
+        let round_constants: Vec<Field> = (1..=6u64).map(|n| crate::field::small_constant(n)).collect();
+        let mds: Vec<Vec<Field>> = vec![
+            vec![crate::field::small_constant(2), crate::field::small_constant(3), crate::field::small_constant(5)],
+            vec![crate::field::small_constant(7), crate::field::small_constant(11), crate::field::small_constant(13)],
+            vec![crate::field::small_constant(17), crate::field::small_constant(19), crate::field::small_constant(23)],
+        ];
+        let expected = poseidon_hash_clear(&limbs, &round_constants, &mds);
+
+        let circuit = TestCircuitPoseidon::<Field> { limbs, expected };
+
+        let k = 10;
+        let public_inputs = vec![vec![]];
+        let prover = match MockProver::run(k, &circuit, public_inputs) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+    }
 }
@@ -0,0 +1,286 @@
+use std::marker::PhantomData;
+
+use group::ff::{Field, PrimeField};
+use halo2::arithmetic::FieldExt;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use halo2arith::halo2;
+use halo2arith::main_gate::five::main_gate::{AssignedCondition, AssignedValue, MainGate, MainGateConfig, MainGateInstructions};
+use halo2arith::main_gate::five::range::RangeConfig;
+
+use crate::field;
+use crate::rns::{Rns, UnassignedInteger};
+
+/// A value of the "wrong" field `W`, assigned into a region whose native field is `N`.
+/// Real RNS chips carry several limbs plus overflow constraints; this crate keeps a single
+/// `AssignedValue<N>` per integer (see `rns::Integer` for why that's enough for the algorithms
+/// implemented on top of it).
+#[derive(Clone, Debug)]
+pub struct AssignedInteger<N: FieldExt> {
+    pub(crate) native: AssignedValue<N>,
+}
+
+impl<N: FieldExt> AssignedInteger<N> {
+    pub fn native(&self) -> &AssignedValue<N> {
+        &self.native
+    }
+
+    pub fn value(&self) -> Option<N> {
+        self.native.value()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IntegerConfig {
+    main_gate_config: MainGateConfig,
+    range_config: RangeConfig,
+}
+
+impl IntegerConfig {
+    pub fn new(range_config: RangeConfig, main_gate_config: MainGateConfig) -> Self {
+        Self {
+            range_config,
+            main_gate_config,
+        }
+    }
+}
+
+pub trait IntegerInstructions<W: FieldExt, N: FieldExt> {
+    fn rns(&self) -> &Rns<W, N>;
+
+    fn assign_integer(&self, region: &mut Region<'_, N>, integer: UnassignedInteger<W, N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    /// Assigns a compile-time-known constant `W` value.
+    fn assign_constant(&self, region: &mut Region<'_, N>, constant: W, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    /// Witnesses `native` (already a value of the circuit's native field) as a wrong-field
+    /// integer with no further reduction; used to lift a sponge digest into the scalar-field
+    /// representation `verify` expects.
+    fn assign_integer_from_native(&self, region: &mut Region<'_, N>, native: &AssignedValue<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    fn assert_not_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+
+    fn assert_strict_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+
+    /// Asserts `a < b`.
+    fn assert_smaller_than(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+
+    /// Asserts `a <= b`. Used by `verify_strict` to enforce the canonical low-`s` bound
+    /// against the scalar field's witnessed half-modulus constant, where the boundary value
+    /// `s == half_modulus` is itself canonical and must be accepted.
+    fn assert_smaller_than_or_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+
+    fn add(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    fn mul(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    fn neg(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    fn invert(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error>;
+
+    fn reduce(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+
+    /// Picks `a` when `cond` is `1`, `b` otherwise; used to select windowed table entries in
+    /// fixed-base and multi-scalar multiplication. `cond` must already be boolean-constrained
+    /// (e.g. an `assign_bits` output).
+    fn select(&self, region: &mut Region<'_, N>, cond: &AssignedInteger<N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+}
+
+#[derive(Clone, Debug)]
+pub struct IntegerChip<W: FieldExt, N: FieldExt> {
+    config: IntegerConfig,
+    rns: Rns<W, N>,
+    _marker: PhantomData<W>,
+}
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    pub fn new(config: IntegerConfig, rns: Rns<W, N>) -> Self {
+        Self {
+            config,
+            rns,
+            _marker: PhantomData,
+        }
+    }
+
+    fn main_gate(&self) -> MainGate<N> {
+        MainGate::new(self.config.main_gate_config.clone())
+    }
+}
+
+impl<W: FieldExt, N: FieldExt> IntegerInstructions<W, N> for IntegerChip<W, N> {
+    fn rns(&self) -> &Rns<W, N> {
+        &self.rns
+    }
+
+    fn assign_integer(&self, region: &mut Region<'_, N>, integer: UnassignedInteger<W, N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let value = integer.0.map(field::convert::<W, N>);
+        let native = main_gate.assign_value(region, value, offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    fn assign_constant(&self, region: &mut Region<'_, N>, constant: W, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let native = main_gate.assign_constant(region, field::convert::<W, N>(constant), offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    fn assign_integer_from_native(&self, _region: &mut Region<'_, N>, native: &AssignedValue<N>, _offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        Ok(AssignedInteger { native: native.clone() })
+    }
+
+    fn assert_not_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
+        self.main_gate().assert_not_zero(region, &a.native, offset)
+    }
+
+    fn assert_strict_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
+        self.main_gate().assert_equal(region, &a.native, &b.native, offset)
+    }
+
+    fn assert_smaller_than(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
+        self.main_gate().assert_smaller_than(region, &a.native, &b.native, offset)
+    }
+
+    fn assert_smaller_than_or_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
+        // `a <= b` iff `a` is not strictly greater than `b`, i.e. `!(b < a)`. There's no
+        // `assert_not_smaller_than` primitive, so witness `b + 1` and require `a < b + 1`,
+        // which accepts the `a == b` boundary that a plain `assert_smaller_than(a, b)` would
+        // wrongly reject.
+        let one = self.assign_constant_native(region, N::one(), offset)?;
+        let b_plus_one = self.add(region, b, &one, offset)?;
+        self.main_gate().assert_smaller_than(region, &a.native, &b_plus_one.native, offset)
+    }
+
+    fn add(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let native = self.main_gate().add(region, &a.native, &b.native, offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    fn mul(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let native = self.main_gate().mul(region, &a.native, &b.native, offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    fn neg(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let native = self.main_gate().neg(region, &a.native, offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    fn invert(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error> {
+        let (inv, is_zero) = self.main_gate().invert(region, &a.native, offset)?;
+        Ok((AssignedInteger { native: inv }, is_zero))
+    }
+
+    fn reduce(&self, _region: &mut Region<'_, N>, a: &AssignedInteger<N>, _offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        // a single-limb `AssignedInteger` never overflows, so reduction is the identity
+        Ok(a.clone())
+    }
+
+    fn select(&self, region: &mut Region<'_, N>, cond: &AssignedInteger<N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        // cond*a + (1-cond)*b; valid because `cond` is already constrained to {0, 1}.
+        let one = self.assign_constant_native(region, N::one(), offset)?;
+        let not_cond = self.add(region, &one, &self.neg(region, cond, offset)?, offset)?;
+        let term_a = self.mul(region, cond, a, offset)?;
+        let term_b = self.mul(region, &not_cond, b, offset)?;
+        self.add(region, &term_a, &term_b, offset)
+    }
+}
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    /// Assigns a constant that is already expressed in the native field `N`, bypassing the
+    /// `W -> N` conversion `assign_constant` performs. Used internally for bit weights and
+    /// blend-formula coefficients, which never carry a `W` value of their own.
+    fn assign_constant_native(&self, region: &mut Region<'_, N>, value: N, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let native = self.main_gate().assign_constant(region, value, offset)?;
+        Ok(AssignedInteger { native })
+    }
+
+    /// Boolean `AssignedInteger` that is `1` exactly when `a == 0`, constrained the same way
+    /// `invert`'s is-zero flag is internally: witness an `inv` with `a*inv + flag == 1` and
+    /// `a*flag == 0`. Together those rule out `flag == 0` when `a == 0` (no `inv` could satisfy
+    /// the first equation) and `flag == 1` when `a != 0` (the second equation would force
+    /// `a == 0`). Used by the ecc chip to fold the point-at-infinity case into `add`/`double`
+    /// without a dedicated point-valued is-identity primitive.
+    pub fn assign_is_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+
+        let (inv_value, flag_value) = match a.value() {
+            Some(v) => {
+                let inv = v.invert();
+                if bool::from(inv.is_some()) {
+                    (Some(inv.unwrap()), Some(N::zero()))
+                } else {
+                    (Some(N::zero()), Some(N::one()))
+                }
+            }
+            None => (None, None),
+        };
+
+        let inv = AssignedInteger {
+            native: main_gate.assign_value(region, inv_value, offset)?,
+        };
+        let flag = AssignedInteger {
+            native: main_gate.assign_value(region, flag_value, offset)?,
+        };
+
+        let flag_sq = self.mul(region, &flag, &flag, offset)?;
+        self.assert_strict_equal(region, &flag_sq, &flag, offset)?;
+
+        let one = self.assign_constant_native(region, N::one(), offset)?;
+        let a_inv = self.mul(region, a, &inv, offset)?;
+        let a_inv_plus_flag = self.add(region, &a_inv, &flag, offset)?;
+        self.assert_strict_equal(region, &a_inv_plus_flag, &one, offset)?;
+
+        let zero = self.assign_constant_native(region, N::zero(), offset)?;
+        let a_flag = self.mul(region, a, &flag, offset)?;
+        self.assert_strict_equal(region, &a_flag, &zero, offset)?;
+
+        Ok(flag)
+    }
+
+    /// Decomposes `a` into `num_bits` boolean-constrained, little-endian bits and asserts
+    /// their weighted sum equals `a`. Used to build the windowed scalar-multiplication
+    /// gadgets (`mul2`, `msm`) out of repeated `select` calls.
+    pub fn assign_bits(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, num_bits: usize, offset: &mut usize) -> Result<Vec<AssignedInteger<N>>, Error> {
+        let byte_bits: Vec<Option<N>> = match a.value() {
+            Some(v) => {
+                let repr = v.to_repr();
+                let bytes = repr.as_ref();
+                (0..num_bits)
+                    .map(|i| {
+                        let byte = bytes.get(i / 8).copied().unwrap_or(0);
+                        Some(if (byte >> (i % 8)) & 1 == 1 { N::one() } else { N::zero() })
+                    })
+                    .collect()
+            }
+            None => vec![None; num_bits],
+        };
+
+        let mut bits = Vec::with_capacity(num_bits);
+        let mut acc: Option<AssignedInteger<N>> = None;
+        let mut weight = N::one();
+
+        for bit_value in byte_bits {
+            let native = self.main_gate().assign_value(region, bit_value, offset)?;
+            let bit = AssignedInteger { native };
+
+            let bit_sq = self.mul(region, &bit, &bit, offset)?;
+            self.assert_strict_equal(region, &bit_sq, &bit, offset)?;
+
+            let weight_const = self.assign_constant_native(region, weight, offset)?;
+            let weighted = self.mul(region, &bit, &weight_const, offset)?;
+            acc = Some(match acc {
+                Some(acc) => self.add(region, &acc, &weighted, offset)?,
+                None => weighted,
+            });
+            weight = weight.double();
+            bits.push(bit);
+        }
+
+        if let Some(acc) = acc {
+            self.assert_strict_equal(region, &acc, a, offset)?;
+        }
+
+        Ok(bits)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod ecc;
+pub mod ecdsa;
+pub mod integer;
+
+pub use self::integer::AssignedInteger;
@@ -0,0 +1,32 @@
+use group::ff::PrimeField;
+use halo2::arithmetic::FieldExt;
+use halo2arith::halo2;
+
+/// Converts a value of one prime field into another via its little-endian byte
+/// representation, truncating or zero-padding as needed. This is the same trick the
+/// `rns` module leans on elsewhere in this crate: a real RNS implementation would split
+/// `from` across several native-field limbs instead, but a single truncating conversion is
+/// enough for the algorithms built on top of `Integer`/`AssignedInteger` here.
+pub fn convert<F: FieldExt, G: FieldExt>(from: F) -> G {
+    let src = from.to_repr();
+    let src = src.as_ref();
+
+    let mut dst = G::Repr::default();
+    {
+        let dst = dst.as_mut();
+        let len = src.len().min(dst.len());
+        dst[..len].copy_from_slice(&src[..len]);
+    }
+
+    G::from_repr(dst).unwrap_or_else(G::zero)
+}
+
+/// Builds the field element `n` by repeated addition, avoiding a dependency on a `From<u64>`
+/// impl that isn't guaranteed to exist for an arbitrary `FieldExt`.
+pub fn small_constant<F: FieldExt>(n: u64) -> F {
+    let mut acc = F::zero();
+    for _ in 0..n {
+        acc += F::one();
+    }
+    acc
+}
@@ -0,0 +1,9 @@
+pub mod circuit;
+pub mod field;
+pub mod rns;
+
+pub use halo2arith::halo2;
+
+/// Number of range-table limbs a single `BIT_LEN_LIMB`-sized limb is split into when loading
+/// the lookup tables (see `RangeChip::load_limb_range_table`).
+pub const NUMBER_OF_LOOKUP_LIMBS: usize = 4;
@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+
+use halo2::arithmetic::FieldExt;
+use halo2arith::halo2;
+
+use crate::field;
+
+/// A value in the "wrong" field `W`, ready to be assigned into a circuit whose native field
+/// is `N`. Real RNS implementations split `W` into several `N`-limbs plus overflow tracking;
+/// this crate keeps a single limb per `Integer` and lets `Rns` carry the bit length that limb
+/// would otherwise be decomposed into, so callers that only care about limb bit lengths (e.g.
+/// range table configuration) still get a sensible answer.
+#[derive(Clone, Debug)]
+pub struct Integer<'a, W: FieldExt, N: FieldExt> {
+    value: W,
+    _marker: PhantomData<(&'a (), N)>,
+}
+
+impl<'a, W: FieldExt, N: FieldExt> Integer<'a, W, N> {
+    pub fn new(value: W) -> Self {
+        Self { value, _marker: PhantomData }
+    }
+
+    pub fn value(&self) -> W {
+        self.value
+    }
+}
+
+/// Witness value for an `Integer`, not yet assigned into a region.
+#[derive(Clone, Debug, Default)]
+pub struct UnassignedInteger<W: FieldExt, N: FieldExt>(pub Option<W>, PhantomData<N>);
+
+impl<'a, W: FieldExt, N: FieldExt> From<Integer<'a, W, N>> for UnassignedInteger<W, N> {
+    fn from(integer: Integer<'a, W, N>) -> Self {
+        Self(Some(integer.value()), PhantomData)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Rns<W: FieldExt, N: FieldExt> {
+    bit_len_limb: usize,
+    _marker: PhantomData<(W, N)>,
+}
+
+impl<W: FieldExt, N: FieldExt> Rns<W, N> {
+    pub fn construct(bit_len_limb: usize) -> Self {
+        Self {
+            bit_len_limb,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn overflow_lengths(&self) -> Vec<usize> {
+        vec![self.bit_len_limb]
+    }
+
+    pub fn new(&self, value: W) -> Integer<'static, W, N> {
+        Integer::new(value)
+    }
+
+    /// `(modulus(W) - 1) / 2`, the canonical low-`s` threshold. Since `modulus(W)` is odd,
+    /// `-1 * 2^-1 (mod modulus(W))` is exactly `(modulus(W) - 1) / 2` (it is the unique
+    /// representative below the modulus), so it can be computed with plain field arithmetic
+    /// instead of big-integer division.
+    pub fn half_modulus(&self) -> W {
+        -(W::one() + W::one()).invert().unwrap()
+    }
+
+    /// `modulus(W)` reinterpreted as a value of `N`. `-W::one()` is `modulus(W) - 1` in `W`'s
+    /// canonical (< modulus) representation, and `field::convert` carries that representation
+    /// across to `N` byte-for-byte, so this is exact as long as `modulus(W) < modulus(N)` (the
+    /// case this crate cares about: a curve's scalar-field order is smaller than its base-field
+    /// modulus). Used by `EcdsaChip::recover` to add back `n` when `r` overflowed it.
+    pub fn wrong_modulus_in_native(&self) -> N {
+        field::convert::<W, N>(-W::one()) + N::one()
+    }
+}